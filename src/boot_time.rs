@@ -0,0 +1,142 @@
+//! A suspend-aware oneshot timer backed by Linux `timerfd`/`CLOCK_BOOTTIME`.
+//!
+//! [`crate::OneshotTimer`] is built on [`tokio::time::Sleep`], whose monotonic clock pauses
+//! while the machine is suspended — a "fire in 30 minutes" timer armed just before a laptop or
+//! phone sleeps can fire far later than intended once it wakes. [`BootTimeOneshotTimer`] instead
+//! wraps a `timerfd` created against `CLOCK_BOOTTIME`, which keeps advancing across suspend, so
+//! elapsed wall-clock time is tracked correctly for wake-alarm style use cases.
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+
+use tokio::io::unix::AsyncFd;
+use tokio::time::Duration;
+
+/// A oneshot timer whose remaining time keeps counting down across system suspend.
+///
+/// Mirrors [`crate::OneshotTimer`]'s `scheduled`/`schedule`/`cancel`/`tick` surface, but every
+/// operation is fallible since it's backed by a real file descriptor.
+#[derive(Debug)]
+pub struct BootTimeOneshotTimer {
+    fd: AsyncFd<OwnedFd>,
+}
+
+impl BootTimeOneshotTimer {
+    /// Create a timer scheduled to expire after `duration`
+    pub fn scheduled(duration: Duration) -> io::Result<Self> {
+        let fd = create_timerfd()?;
+        arm(&fd, Some(duration))?;
+        Ok(Self {
+            fd: AsyncFd::new(fd)?,
+        })
+    }
+
+    /// Create a timer that won't expire until [`BootTimeOneshotTimer::schedule`] is called
+    pub fn expired() -> io::Result<Self> {
+        let fd = create_timerfd()?;
+        Ok(Self {
+            fd: AsyncFd::new(fd)?,
+        })
+    }
+
+    /// Schedule the timer to expire after `duration`
+    pub fn schedule(&mut self, duration: Duration) -> io::Result<()> {
+        arm(self.fd.get_ref(), Some(duration))
+    }
+
+    /// Cancel the timer by arming it with a zero expiration
+    pub fn cancel(&mut self) -> io::Result<()> {
+        arm(self.fd.get_ref(), None)
+    }
+
+    /// Returns a [`std::future::Future`] that resolves once the timer expires
+    pub async fn tick(&mut self) -> io::Result<()> {
+        loop {
+            let mut guard = self.fd.readable_mut().await?;
+            match guard.try_io(|fd| read_expirations(fd.get_ref())) {
+                Ok(result) => return result.map(|_expirations| ()),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+}
+
+fn create_timerfd() -> io::Result<OwnedFd> {
+    // SAFETY: `timerfd_create` with these flags either returns a freshly-opened, owned fd or -1
+    // on error; no preconditions on the caller beyond that.
+    let raw = unsafe {
+        libc::timerfd_create(libc::CLOCK_BOOTTIME, libc::TFD_NONBLOCK | libc::TFD_CLOEXEC)
+    };
+    if raw < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // SAFETY: `raw` is the fd we were just handed above, and nothing else owns it yet.
+    Ok(unsafe { OwnedFd::from_raw_fd(raw) })
+}
+
+/// Arms `fd` to fire once after `duration`, or disarms it (zero expiration) when `None`.
+fn arm(fd: &OwnedFd, duration: Option<Duration>) -> io::Result<()> {
+    // A zero `it_value` disarms a timerfd, so a zero `duration` is armed for 1ns instead.
+    let value = match duration {
+        Some(duration) if duration.is_zero() => Duration::from_nanos(1),
+        Some(duration) => duration,
+        None => Duration::ZERO,
+    };
+    let spec = libc::itimerspec {
+        it_interval: libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        },
+        it_value: libc::timespec {
+            tv_sec: value.as_secs() as libc::time_t,
+            tv_nsec: value.subsec_nanos() as libc::c_long,
+        },
+    };
+    // SAFETY: `fd` is a valid timerfd borrowed for this call, `spec` is a fully-initialized
+    // `itimerspec`, and we pass a null `old_value` since we don't need it back.
+    let result = unsafe { libc::timerfd_settime(fd.as_raw_fd(), 0, &spec, std::ptr::null_mut()) };
+    if result < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Reads and clears the fd's expiration counter; `Ok` means a readable event really was a firing
+/// (as opposed to a spurious wakeup), per the `timerfd` read ABI.
+fn read_expirations(fd: &OwnedFd) -> io::Result<u64> {
+    let mut buf = [0u8; 8];
+    // SAFETY: `buf` is exactly 8 bytes, matching the `u64` expiration-count ABI that
+    // `read(2)` on a timerfd writes into it.
+    let n = unsafe { libc::read(fd.as_raw_fd(), buf.as_mut_ptr().cast(), buf.len()) };
+    if n < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(u64::from_ne_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_schedule_then_tick_resolves() {
+        let mut timer = BootTimeOneshotTimer::expired().expect("create timerfd");
+        timer
+            .schedule(Duration::from_millis(10))
+            .expect("arm timer");
+
+        tokio::time::timeout(Duration::from_millis(500), timer.tick())
+            .await
+            .expect("timer should fire")
+            .expect("tick should succeed");
+    }
+
+    #[tokio::test]
+    async fn test_cancel_suppresses_tick() {
+        let mut timer =
+            BootTimeOneshotTimer::scheduled(Duration::from_millis(10)).expect("create timerfd");
+        timer.cancel().expect("cancel timer");
+
+        let result = tokio::time::timeout(Duration::from_millis(100), timer.tick()).await;
+        assert!(result.is_err(), "a canceled timer should not fire");
+    }
+}
@@ -6,10 +6,27 @@
 //!
 //! This crate provides [`PeriodicTimer`] and [`OneshotTimer`] that aim to make the use of timers more pleasant.
 //! This timers have methods to cancel and restart timers.
+//!
+//! For managing many dynamically created timers behind a single await point, see [`TimerSet`].
+//!
+//! On Linux, the `boot-time` feature additionally provides [`BootTimeOneshotTimer`], which keeps
+//! counting down across system suspend.
+use std::pin::Pin;
 use std::task;
 
-use futures::Future;
-use tokio::time::{interval, sleep_until, Duration, Instant, Interval};
+use futures::stream::FusedStream;
+use futures::{Future, Stream};
+use tokio::time::{
+    interval, interval_at, sleep_until, Duration, Instant, Interval, MissedTickBehavior, Sleep,
+};
+
+#[cfg(all(target_os = "linux", feature = "boot-time"))]
+mod boot_time;
+mod timer_set;
+
+#[cfg(all(target_os = "linux", feature = "boot-time"))]
+pub use boot_time::BootTimeOneshotTimer;
+pub use timer_set::TimerSet;
 
 /// NeverExpire is a future that never unblocks
 #[derive(Default, Debug)]
@@ -33,6 +50,12 @@ impl Future for NeverExpire {
 /// When in [`PeriodicTimer::Started`] state the timer will expire every interval duration but
 /// when in [`PeriodicTimer::Stopped`] it won't expire until the timer is started again.
 ///
+/// By default a missed tick (the consumer falling behind) is caught up with
+/// [`MissedTickBehavior::Burst`], matching [`tokio::time::interval`]. Use
+/// [`PeriodicTimer::start_with_behavior`]/[`PeriodicTimer::started_with_behavior`] to pick
+/// [`MissedTickBehavior::Delay`] or [`MissedTickBehavior::Skip`] instead; the chosen behavior is
+/// preserved across [`PeriodicTimer::stop`]/[`PeriodicTimer::start`].
+///
 /// ```
 /// use async_timers::PeriodicTimer;
 /// use tokio::time::{Duration, timeout};
@@ -56,43 +79,136 @@ impl Future for NeverExpire {
 ///     assert!(result.is_err(), "Timeout should occur since timer is stopped");
 /// }
 /// ```
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub enum PeriodicTimer {
     Started(Interval),
-    #[default]
-    Stopped,
+    Stopped(MissedTickBehavior),
+}
+
+impl Default for PeriodicTimer {
+    fn default() -> Self {
+        Self::Stopped(MissedTickBehavior::Burst)
+    }
 }
 
 impl PeriodicTimer {
     /// Create started timer with the given `period`
     pub fn started(period: Duration) -> Self {
-        Self::Started(interval(period))
+        Self::started_with_behavior(period, MissedTickBehavior::Burst)
+    }
+
+    /// Create a started timer with the given `period` and [`MissedTickBehavior`]
+    pub fn started_with_behavior(period: Duration, behavior: MissedTickBehavior) -> Self {
+        let mut interval = interval(period);
+        interval.set_missed_tick_behavior(behavior);
+        Self::Started(interval)
+    }
+
+    /// Create a started timer whose first tick fires at the given `start` [`Instant`] rather
+    /// than one `period` from now
+    pub fn started_at(start: Instant, period: Duration) -> Self {
+        Self::started_at_with_behavior(start, period, MissedTickBehavior::Burst)
+    }
+
+    /// Like [`PeriodicTimer::started_at`], with an explicit [`MissedTickBehavior`]
+    pub fn started_at_with_behavior(
+        start: Instant,
+        period: Duration,
+        behavior: MissedTickBehavior,
+    ) -> Self {
+        let mut interval = interval_at(start, period);
+        interval.set_missed_tick_behavior(behavior);
+        Self::Started(interval)
     }
 
     /// Create stopped timer
     pub fn stopped() -> Self {
-        Self::Stopped
+        Self::Stopped(MissedTickBehavior::Burst)
     }
 
-    /// Start the timer with given `period`
+    /// Start the timer with given `period`, preserving the current [`MissedTickBehavior`]
     pub fn start(&mut self, period: Duration) {
-        *self = Self::started(period);
+        let behavior = self.missed_tick_behavior();
+        *self = Self::started_with_behavior(period, behavior);
+    }
+
+    /// Start the timer with the given `period` and [`MissedTickBehavior`]
+    pub fn start_with_behavior(&mut self, period: Duration, behavior: MissedTickBehavior) {
+        *self = Self::started_with_behavior(period, behavior);
+    }
+
+    /// Start the timer so its first tick fires at the given `start` [`Instant`], preserving
+    /// the current [`MissedTickBehavior`]
+    pub fn start_at(&mut self, start: Instant, period: Duration) {
+        let behavior = self.missed_tick_behavior();
+        *self = Self::started_at_with_behavior(start, period, behavior);
+    }
+
+    /// Like [`PeriodicTimer::start_at`], with an explicit [`MissedTickBehavior`]
+    pub fn start_at_with_behavior(
+        &mut self,
+        start: Instant,
+        period: Duration,
+        behavior: MissedTickBehavior,
+    ) {
+        *self = Self::started_at_with_behavior(start, period, behavior);
     }
 
-    /// Stop the timer
+    /// Stop the timer, preserving the current [`MissedTickBehavior`] for the next `start`
     pub fn stop(&mut self) {
-        *self = Self::stopped()
+        let behavior = self.missed_tick_behavior();
+        *self = Self::Stopped(behavior);
+    }
+
+    /// The timer's current [`MissedTickBehavior`], preserved across `stop`/`start`
+    pub fn missed_tick_behavior(&self) -> MissedTickBehavior {
+        match self {
+            Self::Started(interval) => interval.missed_tick_behavior(),
+            Self::Stopped(behavior) => *behavior,
+        }
+    }
+
+    /// The timer's period, or `None` if it's currently [`PeriodicTimer::Stopped`]
+    pub fn period(&self) -> Option<Duration> {
+        match self {
+            Self::Started(interval) => Some(interval.period()),
+            Self::Stopped(_) => None,
+        }
     }
 
     /// Returns a [`Future`] that will expire based on timer's state
     pub async fn tick(&mut self) -> Instant {
         match self {
             Self::Started(interval) => interval.tick().await,
-            Self::Stopped => NeverExpire::default().await,
+            Self::Stopped(_) => NeverExpire::default().await,
         }
     }
 }
 
+/// Yields an [`Instant`] on every interval while [`PeriodicTimer::Started`], and never
+/// yields while [`PeriodicTimer::Stopped`].
+impl Stream for PeriodicTimer {
+    type Item = Instant;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut task::Context<'_>,
+    ) -> task::Poll<Option<Self::Item>> {
+        match self.get_mut() {
+            Self::Started(interval) => interval.poll_tick(cx).map(Some),
+            Self::Stopped(_) => task::Poll::Pending,
+        }
+    }
+}
+
+/// A [`PeriodicTimer::Stopped`] timer is considered terminated so it can be dropped into
+/// `futures::select!` without being polled once it's no longer running.
+impl FusedStream for PeriodicTimer {
+    fn is_terminated(&self) -> bool {
+        matches!(self, Self::Stopped(_))
+    }
+}
+
 /// OneshotTimer expires once after a given duration
 ///
 /// OneshotTimer is used for tasks that need to be executed once after some delay.
@@ -100,6 +216,11 @@ impl PeriodicTimer {
 /// In [`OneshotTimer::Scheduled`] state it will expire *once* and transition into
 /// [`OneshotTimer::Expired`] state.
 ///
+/// A running timer can also be frozen with [`OneshotTimer::pause`], which moves it to
+/// [`OneshotTimer::Paused`] and remembers the remaining duration; [`OneshotTimer::resume`] then
+/// re-arms it for exactly that long from the current time, so time spent paused doesn't count
+/// against it.
+///
 /// ```
 /// use async_timers::OneshotTimer;
 /// use tokio::time::{Duration, timeout};
@@ -123,7 +244,11 @@ impl PeriodicTimer {
 /// ```
 #[derive(Default, Debug)]
 pub enum OneshotTimer {
-    Scheduled(Instant),
+    /// Holds the live [`Sleep`] so it keeps its timer-wheel registration across polls; read its
+    /// deadline with [`Sleep::deadline`]. Previously this variant held the deadline `Instant`
+    /// directly — code matching on it to read the deadline should switch to `.deadline()`.
+    Scheduled(Pin<Box<Sleep>>),
+    Paused(Duration),
     #[default]
     Expired,
 }
@@ -131,7 +256,12 @@ pub enum OneshotTimer {
 impl OneshotTimer {
     /// Create a timer scheduled to be expired after `duration`
     pub fn scheduled(duration: Duration) -> Self {
-        Self::Scheduled(Instant::now() + duration)
+        Self::scheduled_at(Instant::now() + duration)
+    }
+
+    /// Create a timer scheduled to expire at the given `Instant`
+    pub fn scheduled_at(instant: Instant) -> Self {
+        Self::Scheduled(Box::pin(sleep_until(instant)))
     }
 
     /// Create a timer that won't expire
@@ -144,25 +274,120 @@ impl OneshotTimer {
         *self = Self::scheduled(duration);
     }
 
+    /// Schedule the timer to expire at the given `Instant`
+    pub fn schedule_at(&mut self, instant: Instant) {
+        *self = Self::scheduled_at(instant);
+    }
+
     /// Cancel the timer
     pub fn cancel(&mut self) {
         *self = Self::expired()
     }
 
+    /// Freeze a running timer, recording the remaining duration until it would have expired.
+    /// Has no effect unless the timer is currently [`OneshotTimer::Scheduled`].
+    pub fn pause(&mut self) {
+        if let Self::Scheduled(sleep) = self {
+            let remaining = sleep.deadline().saturating_duration_since(Instant::now());
+            *self = Self::Paused(remaining);
+        }
+    }
+
+    /// Resume a paused timer, scheduling it for the remaining duration it had when paused. Has
+    /// no effect unless the timer is currently [`OneshotTimer::Paused`].
+    pub fn resume(&mut self) {
+        if let Self::Paused(remaining) = self {
+            *self = Self::scheduled(*remaining);
+        }
+    }
+
     /// Returns a [`Future`] that will expire based on timer's state
     pub async fn tick(&mut self) {
         match self {
-            Self::Scheduled(instant) => {
-                sleep_until(*instant).await;
+            Self::Scheduled(sleep) => {
+                sleep.as_mut().await;
                 *self = Self::expired();
             }
-            Self::Expired => {
+            Self::Paused(_) | Self::Expired => {
                 NeverExpire::default().await;
             }
         }
     }
 }
 
+/// Yields `()` exactly once when the timer expires, then ends.
+impl Stream for OneshotTimer {
+    type Item = ();
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut task::Context<'_>,
+    ) -> task::Poll<Option<Self::Item>> {
+        match &mut *self {
+            Self::Scheduled(sleep) => {
+                futures::ready!(sleep.as_mut().poll(cx));
+                *self = Self::expired();
+                task::Poll::Ready(Some(()))
+            }
+            Self::Paused(_) => task::Poll::Pending,
+            Self::Expired => task::Poll::Ready(None),
+        }
+    }
+}
+
+/// A [`OneshotTimer::Expired`] timer has ended its stream and is considered terminated.
+impl FusedStream for OneshotTimer {
+    fn is_terminated(&self) -> bool {
+        matches!(self, Self::Expired)
+    }
+}
+
+/// Error returned by [`with_timeout`] when the timeout elapses before the future completes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeoutError;
+
+impl std::fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "future timed out")
+    }
+}
+
+impl std::error::Error for TimeoutError {}
+
+/// Races `fut` against a [`OneshotTimer`] scheduled for `duration`, resolving to
+/// [`TimeoutError`] if `fut` hasn't completed by then.
+///
+/// This mirrors [`tokio::time::timeout`], built from the crate's own timers rather than
+/// tokio's, so it composes with [`OneshotTimer::schedule_at`] and friends.
+///
+/// ```
+/// use async_timers::with_timeout;
+/// use tokio::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let result = with_timeout(Duration::from_millis(100), async {
+///         tokio::time::sleep(Duration::from_millis(500)).await;
+///     })
+///     .await;
+///     assert!(result.is_err(), "Timeout should occur since the future is too slow");
+///
+///     let result = with_timeout(Duration::from_millis(500), async { 42 }).await;
+///     assert_eq!(result, Ok(42));
+/// }
+/// ```
+pub async fn with_timeout<F: Future>(
+    duration: Duration,
+    fut: F,
+) -> Result<F::Output, TimeoutError> {
+    let mut timer = OneshotTimer::scheduled(duration);
+    tokio::select! {
+        biased;
+        output = fut => Ok(output),
+        _ = timer.tick() => Err(TimeoutError),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -206,6 +431,106 @@ mod tests {
         assert!(!timer2_expired, "timer2 should not have expired");
     }
 
+    #[tokio::test]
+    async fn test_periodic_timer_missed_tick_behavior() {
+        // After the consumer falls behind by several periods, `Burst` fires every missed tick
+        // back-to-back, while `Delay`/`Skip` only fire one immediate catch-up tick.
+        async fn immediate_ticks(timer: &mut PeriodicTimer, attempts: usize) -> usize {
+            let mut immediate = 0;
+            for _ in 0..attempts {
+                match tokio::time::timeout(Duration::from_millis(5), timer.tick()).await {
+                    Ok(_) => immediate += 1,
+                    Err(_) => break,
+                }
+            }
+            immediate
+        }
+
+        let mut burst = PeriodicTimer::started_with_behavior(
+            Duration::from_millis(20),
+            MissedTickBehavior::Burst,
+        );
+        burst.tick().await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let burst_immediate = immediate_ticks(&mut burst, 10).await;
+        assert!(
+            burst_immediate >= 3,
+            "Burst should fire back-to-back for each missed tick, got {burst_immediate}"
+        );
+
+        let mut delay = PeriodicTimer::started_with_behavior(
+            Duration::from_millis(20),
+            MissedTickBehavior::Delay,
+        );
+        delay.tick().await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let delay_immediate = immediate_ticks(&mut delay, 10).await;
+        assert_eq!(
+            delay_immediate, 1,
+            "Delay should fire exactly one immediate catch-up tick, got {delay_immediate}"
+        );
+
+        let mut skip = PeriodicTimer::started_with_behavior(
+            Duration::from_millis(20),
+            MissedTickBehavior::Skip,
+        );
+        skip.tick().await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let skip_immediate = immediate_ticks(&mut skip, 10).await;
+        assert_eq!(
+            skip_immediate, 1,
+            "Skip should fire exactly one immediate catch-up tick, got {skip_immediate}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_periodic_timer_behavior_and_period_preserved_across_stop_start() {
+        let mut timer = PeriodicTimer::started_with_behavior(
+            Duration::from_millis(50),
+            MissedTickBehavior::Skip,
+        );
+        assert_eq!(timer.missed_tick_behavior(), MissedTickBehavior::Skip);
+        assert_eq!(timer.period(), Some(Duration::from_millis(50)));
+
+        timer.stop();
+        assert_eq!(timer.period(), None);
+        assert_eq!(timer.missed_tick_behavior(), MissedTickBehavior::Skip);
+
+        timer.start(Duration::from_millis(30));
+        assert_eq!(timer.period(), Some(Duration::from_millis(30)));
+        assert_eq!(
+            timer.missed_tick_behavior(),
+            MissedTickBehavior::Skip,
+            "start() should preserve the behavior chosen before stop()"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_oneshot_scheduled_at_past_instant_fires_immediately() {
+        let past = Instant::now() - Duration::from_secs(1);
+        let mut timer = OneshotTimer::scheduled_at(past);
+
+        let result = tokio::time::timeout(Duration::from_millis(20), timer.tick()).await;
+        assert!(
+            result.is_ok(),
+            "a timer scheduled at a past Instant should fire immediately"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_periodic_started_at_aligns_first_tick_to_instant() {
+        // A period far longer than the time until `start` proves the first tick is driven by
+        // `start`, not by one full `period` from the call to `started_at`.
+        let start = Instant::now() + Duration::from_millis(30);
+        let mut timer = PeriodicTimer::started_at(start, Duration::from_secs(10));
+
+        let result = tokio::time::timeout(Duration::from_millis(200), timer.tick()).await;
+        assert!(
+            result.is_ok(),
+            "first tick should fire at `start`, not one period later"
+        );
+    }
+
     #[tokio::test]
     async fn test_oneshot_timer() {
         let mut timer1 = OneshotTimer::expired();
@@ -280,18 +605,94 @@ mod tests {
 
         match timer1 {
             OneshotTimer::Scheduled(_) => {}
-            OneshotTimer::Expired => assert!(false, "Should be in scheduled state"),
+            OneshotTimer::Paused(_) => panic!("Should be in scheduled state"),
+            OneshotTimer::Expired => panic!("Should be in scheduled state"),
         }
 
         let result = tokio::time::timeout(Duration::from_millis(3500), timer1.tick()).await;
         assert!(result.is_ok(), "Should not timeout");
 
         match timer1 {
-            OneshotTimer::Scheduled(_) => assert!(false, "Timer should be in expired state"),
+            OneshotTimer::Scheduled(_) => panic!("Timer should be in expired state"),
+            OneshotTimer::Paused(_) => panic!("Timer should be in expired state"),
             OneshotTimer::Expired => {}
         }
     }
 
+    #[tokio::test]
+    async fn test_oneshot_pause_resume() {
+        let mut timer = OneshotTimer::scheduled(Duration::from_millis(200));
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        timer.pause();
+
+        match timer {
+            OneshotTimer::Paused(remaining) => assert!(remaining <= Duration::from_millis(150)),
+            _ => panic!("Timer should be paused"),
+        }
+
+        // Time spent paused should not count against the remaining duration.
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        let result = tokio::time::timeout(Duration::from_millis(50), timer.tick()).await;
+        assert!(result.is_err(), "Paused timer should not expire");
+
+        timer.resume();
+
+        let result = tokio::time::timeout(Duration::from_millis(500), timer.tick()).await;
+        assert!(
+            result.is_ok(),
+            "Resumed timer should expire after its remaining duration"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_oneshot_timer_stream() {
+        use futures::StreamExt;
+
+        let mut timer = OneshotTimer::scheduled(Duration::from_millis(50));
+        let result = tokio::time::timeout(Duration::from_millis(500), timer.next()).await;
+        assert_eq!(
+            result.expect("stream should yield once the timer elapses"),
+            Some(())
+        );
+
+        let result = tokio::time::timeout(Duration::from_millis(100), timer.next()).await;
+        assert_eq!(
+            result.expect("an expired timer's stream ends rather than hanging"),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_oneshot_timer_select_all() {
+        use futures::stream::select_all;
+        use futures::StreamExt;
+
+        let slow = OneshotTimer::scheduled(Duration::from_millis(500));
+        let fast = OneshotTimer::scheduled(Duration::from_millis(50));
+
+        let mut timers = select_all(vec![slow, fast]);
+        let result = tokio::time::timeout(Duration::from_millis(200), timers.next()).await;
+        assert_eq!(
+            result.expect("the faster of the two timers should wake the combined stream"),
+            Some(())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_periodic_timer_stream() {
+        use futures::StreamExt;
+
+        let mut timer = PeriodicTimer::started(Duration::from_millis(20));
+        for _ in 0..3 {
+            let result = tokio::time::timeout(Duration::from_millis(500), timer.next()).await;
+            assert!(result.is_ok(), "stream should yield on every interval tick");
+        }
+
+        timer.stop();
+        assert!(timer.is_terminated());
+    }
+
     #[tokio::test]
     async fn test_my_task() {
         struct MyTask {
@@ -0,0 +1,183 @@
+//! [`TimerSet`] multiplexes many independently scheduled, keyed timers behind a single await
+//! point, instead of requiring one `select!` arm per timer.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+use tokio::time::{sleep_until, Duration, Instant};
+
+/// How many stale (canceled/rescheduled) heap entries [`TimerSet::next`] will skip past before
+/// yielding back to the executor, so a burst of expirations doesn't starve other tasks.
+const MAX_SKIPPED_BEFORE_YIELD: usize = 10;
+
+/// A set of keyed timers multiplexed behind a single `async fn next(&mut self) -> (K, T)`.
+///
+/// Where [`crate::PeriodicTimer`] and [`crate::OneshotTimer`] each need their own `select!` arm,
+/// `TimerSet` is built for managing many dynamically created timeouts (e.g. one per connection)
+/// without growing the `select!` by hand. Internally it's a min-heap of `(Instant, K)` deadlines
+/// plus a map from `K` to its current `(Instant, T)`; `schedule` overwrites any prior entry for
+/// a key and `cancel` removes it, leaving the stale heap entry to be lazily skipped by `next`.
+///
+/// ```
+/// use async_timers::TimerSet;
+/// use tokio::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut timers = TimerSet::new();
+///
+///     timers.schedule(1, Duration::from_millis(20), "first");
+///     timers.schedule(2, Duration::from_millis(10), "second");
+///
+///     let (key, payload) = timers.next().await;
+///     assert_eq!((key, payload), (2, "second"));
+///
+///     let (key, payload) = timers.next().await;
+///     assert_eq!((key, payload), (1, "first"));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TimerSet<K, T> {
+    heap: BinaryHeap<Reverse<(Instant, K)>>,
+    entries: HashMap<K, (Instant, T)>,
+}
+
+impl<K, T> Default for TimerSet<K, T> {
+    fn default() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<K, T> TimerSet<K, T>
+where
+    K: Eq + Hash + Ord + Clone,
+{
+    /// Create an empty `TimerSet`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if there are no scheduled timers.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of currently scheduled timers.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Schedule `payload` to be returned by [`TimerSet::next`] after `duration`, overwriting any
+    /// prior entry for `key`.
+    pub fn schedule(&mut self, key: K, duration: Duration, payload: T) {
+        let deadline = Instant::now() + duration;
+        self.heap.push(Reverse((deadline, key.clone())));
+        self.entries.insert(key, (deadline, payload));
+    }
+
+    /// Cancel the timer for `key`, returning its payload if it was still scheduled.
+    pub fn cancel(&mut self, key: &K) -> Option<T> {
+        self.entries.remove(key).map(|(_, payload)| payload)
+    }
+
+    /// Waits for the earliest-scheduled timer to expire and returns its `(key, payload)`.
+    ///
+    /// When the set is empty this awaits forever, so it's safe to use as one arm of a `select!`
+    /// alongside other work.
+    pub async fn next(&mut self) -> (K, T) {
+        let mut skipped = 0;
+        loop {
+            let Some(&Reverse((deadline, _))) = self.heap.peek() else {
+                std::future::pending::<()>().await;
+                unreachable!("pending() never resolves");
+            };
+
+            sleep_until(deadline).await;
+
+            let Reverse((deadline, key)) = self.heap.pop().expect("heap was non-empty");
+
+            match self.entries.get(&key) {
+                Some(&(entry_deadline, _)) if entry_deadline == deadline => {
+                    let (_, payload) = self.entries.remove(&key).expect("checked above");
+                    return (key, payload);
+                }
+                // Stale entry: `key` was canceled or rescheduled after this was pushed.
+                _ => {
+                    skipped += 1;
+                    if skipped >= MAX_SKIPPED_BEFORE_YIELD {
+                        skipped = 0;
+                        tokio::task::yield_now().await;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_len_and_is_empty() {
+        let mut timers = TimerSet::new();
+        assert!(timers.is_empty());
+        assert_eq!(timers.len(), 0);
+
+        timers.schedule(1, Duration::from_secs(10), "a");
+        timers.schedule(2, Duration::from_secs(10), "b");
+
+        assert!(!timers.is_empty());
+        assert_eq!(timers.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_suppresses_fire() {
+        let mut timers = TimerSet::new();
+        timers.schedule(1, Duration::from_millis(10), "canceled");
+        timers.schedule(2, Duration::from_millis(50), "survivor");
+
+        assert_eq!(timers.cancel(&1), Some("canceled"));
+        assert_eq!(timers.len(), 1);
+
+        let (key, payload) = tokio::time::timeout(Duration::from_millis(500), timers.next())
+            .await
+            .expect("the surviving timer should still fire");
+        assert_eq!((key, payload), (2, "survivor"));
+    }
+
+    #[tokio::test]
+    async fn test_reschedule_skips_stale_heap_entry() {
+        let mut timers = TimerSet::new();
+        timers.schedule(1, Duration::from_millis(10), "stale");
+        // Overwrite the same key before it fires; the first heap entry is now stale.
+        timers.schedule(1, Duration::from_millis(50), "fresh");
+
+        let (key, payload) = tokio::time::timeout(Duration::from_millis(500), timers.next())
+            .await
+            .expect("the rescheduled timer should fire");
+        assert_eq!((key, payload), (1, "fresh"));
+    }
+
+    #[tokio::test]
+    async fn test_next_skips_many_stale_entries_past_the_yield_threshold() {
+        let mut timers: TimerSet<u32, &str> = TimerSet::new();
+        for key in 0..(MAX_SKIPPED_BEFORE_YIELD as u32 * 2) {
+            timers.schedule(key, Duration::from_millis(10), "stale");
+        }
+        let survivor_key = MAX_SKIPPED_BEFORE_YIELD as u32 * 2;
+        timers.schedule(survivor_key, Duration::from_millis(10), "survivor");
+
+        for key in 0..(MAX_SKIPPED_BEFORE_YIELD as u32 * 2) {
+            timers.cancel(&key);
+        }
+
+        let (key, payload) = tokio::time::timeout(Duration::from_millis(500), timers.next())
+            .await
+            .expect("the single surviving entry should still be returned");
+        assert_eq!((key, payload), (survivor_key, "survivor"));
+    }
+}